@@ -1,5 +1,127 @@
 use super::{r#trait::*, r#type::*};
-use std::thread::{JoinHandle, spawn};
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, RefCell};
+use std::panic::{PanicHookInfo, set_hook, take_hook};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Once, OnceLock};
+use std::thread::{JoinHandle, sleep, spawn};
+
+/// The type of a process panic hook, as accepted by `std::panic::set_hook`.
+type PanicHook = Box<dyn Fn(&PanicHookInfo) + Send + Sync>;
+
+static SILENT: AtomicBool = AtomicBool::new(false);
+static SILENT_HANDLER: Mutex<Option<fn(&PanicHookInfo)>> = Mutex::new(None);
+static ORIGINAL_HOOK: OnceLock<PanicHook> = OnceLock::new();
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Set for the whole body of a `recoverable_spawn*` worker thread (its function, error
+    /// handler and `finally`, while its `SilentGuard` is alive), so the
+    /// process-wide hook installed by `ensure_panic_hook_installed` can tell a worker's panic
+    /// apart from one on an unrelated thread and leave the latter untouched.
+    static IN_RECOVERABLE_WORKER: Cell<bool> = const { Cell::new(false) };
+    /// Set for the duration of `run_function_with_location`, so the process-wide hook also
+    /// records the panic's location/backtrace for that call, on top of its normal behavior.
+    static CAPTURE_PANIC_CONTEXT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs the crate's single process-wide panic hook, exactly once (guarded by `Once`),
+/// saving whatever hook was previously registered so every panic not being silenced still
+/// reaches it. Because the hook is installed once and never swapped out again, concurrent
+/// `recoverable_spawn*` workers can't race each other over `take_hook`/`set_hook` the way
+/// repeatedly installing and restoring a hook per-worker would.
+fn ensure_panic_hook_installed() {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = take_hook();
+        let _ = ORIGINAL_HOOK.set(previous_hook);
+        set_hook(Box::new(|info| {
+            if CAPTURE_PANIC_CONTEXT.with(|cell| cell.get()) {
+                if let Some(location) = info.location() {
+                    let location: (String, u32, u32) =
+                        (location.file().to_string(), location.line(), location.column());
+                    LAST_PANIC_LOCATION.with(|cell| *cell.borrow_mut() = Some(location));
+                }
+                LAST_PANIC_BACKTRACE.with(|cell| *cell.borrow_mut() = Some(Backtrace::capture()));
+            }
+            let silenced: bool =
+                IN_RECOVERABLE_WORKER.with(|cell| cell.get()) && SILENT.load(Ordering::SeqCst);
+            if silenced {
+                if let Some(handler) = *SILENT_HANDLER.lock().unwrap() {
+                    handler(info);
+                }
+            } else if let Some(original_hook) = ORIGINAL_HOOK.get() {
+                original_hook(info);
+            }
+        }));
+    });
+}
+
+/// Enables or disables quiet mode process-wide.
+///
+/// While enabled, `recoverable_spawn*` workers route their panics to the handler set via
+/// `set_silent_handler` (if any) instead of letting the default hook print them to stderr.
+/// Panics on threads outside a `recoverable_spawn*` worker are never affected, since the
+/// process-wide hook only silences a panic when `IN_RECOVERABLE_WORKER` is set for the
+/// panicking thread.
+///
+/// - `silent`: `true` to suppress the default panic output from recoverable workers, `false`
+///   to let it through again.
+pub fn set_silent(silent: bool) {
+    SILENT.store(silent, Ordering::SeqCst);
+}
+
+/// Sets the global handler invoked with each `PanicHookInfo` while quiet mode is enabled.
+/// Pass `None` to drop panics silently with no reporting at all.
+///
+/// - `handler`: A function pointer invoked in place of the default panic hook, or `None`.
+pub fn set_silent_handler(handler: Option<fn(&PanicHookInfo)>) {
+    *SILENT_HANDLER.lock().unwrap() = handler;
+}
+
+/// RAII guard marking the current thread as running a recoverable worker for the lifetime of
+/// the guard, so the process-wide panic hook installed by `ensure_panic_hook_installed` knows
+/// whether to apply quiet mode to a panic on this thread.
+struct SilentGuard;
+
+impl SilentGuard {
+    fn acquire() -> Self {
+        ensure_panic_hook_installed();
+        IN_RECOVERABLE_WORKER.with(|cell| cell.set(true));
+        Self
+    }
+}
+
+impl Drop for SilentGuard {
+    fn drop(&mut self) {
+        IN_RECOVERABLE_WORKER.with(|cell| cell.set(false));
+    }
+}
+
+/// Derives a string description of a panic payload without consuming it, so the original
+/// `BoxAnySend` can still be stored in a `SpawnResult` afterward.
+fn spawn_error_to_string_ref(err: &BoxAnySend) -> String {
+    match err.downcast_ref::<&str>() {
+        Some(str_slice) => str_slice.to_string(),
+        None => match err.downcast_ref::<String>() {
+            Some(string) => string.to_owned(),
+            None => format!("{:?}", err),
+        },
+    }
+}
+
+thread_local! {
+    /// Holds the `(file, line, column)` of the most recent panic on this thread, populated by
+    /// the process-wide hook while `run_function_with_location` has `CAPTURE_PANIC_CONTEXT` set.
+    static LAST_PANIC_LOCATION: RefCell<Option<(String, u32, u32)>> = const { RefCell::new(None) };
+    /// Holds the backtrace captured at the moment of the most recent panic on this thread,
+    /// populated by the process-wide hook while `run_function_with_location` has
+    /// `CAPTURE_PANIC_CONTEXT` set.
+    static LAST_PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+    /// Holds the stringified message of the most recent panic caught by `catch_panic` on this
+    /// thread, polled via `take_last_error`/`last_error_length` instead of propagating.
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
 
 /// Executes a recoverable function within a panic-safe context.
 ///
@@ -36,6 +158,63 @@ pub fn spawn_error_to_string(err: BoxAnySend) -> String {
     }
 }
 
+/// Runs a recoverable function inline, on the calling thread, without spawning. Intended for
+/// embedding recoverable closures behind a C ABI, where unwinding across the FFI boundary is
+/// undefined behavior: a panic is caught and its message stashed in a thread-local, retrievable
+/// afterward with `take_last_error`/`last_error_length`, instead of propagating.
+///
+/// - `func`: A function implementing the `RecoverableFunction` trait.
+/// - Returns: `true` if `func` completed without panicking, `false` if it panicked (in which
+///   case the panic message is available via `take_last_error`).
+pub fn catch_panic<F: RecoverableFunction>(func: F) -> bool {
+    match run_function(func) {
+        Ok(()) => true,
+        Err(err) => {
+            let message: String = spawn_error_to_string(err);
+            LAST_ERROR.with(|cell| *cell.borrow_mut() = Some(message));
+            false
+        }
+    }
+}
+
+/// Takes the message of the most recent panic caught by `catch_panic` on this thread,
+/// leaving `None` in its place. A later `catch_panic` call that does not panic leaves an
+/// earlier message in place.
+///
+/// - Returns: The stringified panic message, or `None` if `catch_panic` has not recorded one
+///   (either none has panicked yet, or it was already taken).
+pub fn take_last_error() -> Option<String> {
+    LAST_ERROR.with(|cell| cell.borrow_mut().take())
+}
+
+/// Reports the length, in bytes, of the panic message currently recorded by `catch_panic`,
+/// without consuming it. Useful for C callers that need to size a buffer before calling
+/// `take_last_error`.
+///
+/// - Returns: The byte length of the stored message, or `0` if none is recorded.
+pub fn last_error_length() -> usize {
+    LAST_ERROR.with(|cell| cell.borrow().as_ref().map_or(0, |message| message.len()))
+}
+
+/// Executes a recoverable function like `run_function`, but additionally records the
+/// `Location` and backtrace of a panic (if any) into the `LAST_PANIC_LOCATION` /
+/// `LAST_PANIC_BACKTRACE` thread-locals for the duration of the call, by setting
+/// `CAPTURE_PANIC_CONTEXT` for the process-wide hook to notice. The hook still forwards (or
+/// silences, per `set_silent`) the panic exactly as it would for any other recoverable worker —
+/// capturing the context is additive, not a replacement for the normal reporting path.
+///
+/// - `func`: A function implementing the `RecoverableFunction` trait.
+/// - Returns: A `SpawnResult` indicating the success or failure of the function execution.
+fn run_function_with_location<F: RecoverableFunction>(func: F) -> SpawnResult {
+    ensure_panic_hook_installed();
+    LAST_PANIC_LOCATION.with(|cell| *cell.borrow_mut() = None);
+    LAST_PANIC_BACKTRACE.with(|cell| *cell.borrow_mut() = None);
+    CAPTURE_PANIC_CONTEXT.with(|cell| cell.set(true));
+    let result: SpawnResult = run_function(func);
+    CAPTURE_PANIC_CONTEXT.with(|cell| cell.set(false));
+    result
+}
+
 /// Spawns a new thread to run the provided function `function` in a recoverable manner.
 /// If the function `function` panics during execution, the panic will be caught, and the thread
 /// will terminate without crashing the entire program.
@@ -59,6 +238,7 @@ where
     F: RecoverableFunction,
 {
     spawn(|| {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
         let _: SpawnResult = run_function(function);
     })
 }
@@ -74,6 +254,7 @@ where
     E: ErrorHandlerFunction,
 {
     spawn(|| {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
         let run_result: SpawnResult = run_function(function);
         if let Err(err) = run_result {
             let err_string: String = spawn_error_to_string(err);
@@ -93,6 +274,7 @@ where
     L: RecoverableFunction,
 {
     spawn(|| {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
         let run_result: SpawnResult = run_function(function);
         if let Err(err) = run_result {
             let err_string: String = spawn_error_to_string(err);
@@ -101,3 +283,373 @@ where
         let _: SpawnResult = run_function(finally);
     })
 }
+
+/// Spawns a recoverable function that is automatically re-run if it panics, up to
+/// `policy.max_attempts` times (always at least once, even when `max_attempts` is `0`),
+/// sleeping `policy.backoff` (scaled by `policy.multiplier` when set) between attempts.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `policy`: A `RetryPolicy` describing how many attempts to make and how long to wait between them.
+/// - `error_handle_function`: A function invoked with the last panic message once `policy.max_attempts`
+///   has been exhausted without a successful run.
+/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+pub fn recoverable_spawn_retry<F, E>(
+    function: F,
+    mut policy: RetryPolicy,
+    error_handle_function: E,
+) -> JoinHandle<()>
+where
+    F: RecoverableFunction,
+    E: ErrorHandlerFunction,
+{
+    spawn(move || {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
+        let attempts: usize = policy.max_attempts.max(1);
+        for attempt in 0..attempts {
+            // Can't call `run_function(function)` here: it takes its argument by value, and
+            // `function` must survive to be called again on a later attempt. `Fn()` allows
+            // calling it by reference any number of times, so the catch_unwind body is inlined
+            // instead of moving `function` into `run_function` on the first attempt.
+            let run_result: SpawnResult =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    function();
+                }));
+            match run_result {
+                Ok(()) => return,
+                Err(err) => {
+                    if attempt + 1 == attempts {
+                        let err_string: String = spawn_error_to_string(err);
+                        let _: SpawnResult =
+                            run_error_handle_function(error_handle_function, &err_string);
+                        return;
+                    }
+                    if let Some(backoff) = policy.backoff {
+                        sleep(backoff);
+                    }
+                    policy.advance();
+                }
+            }
+        }
+    })
+}
+
+/// Spawns a recoverable function with a context-aware error-handling function in a new thread.
+/// Unlike `recoverable_spawn_catch`, the handler receives a `PanicContext` carrying the panic
+/// message together with its source location and, when available, a captured backtrace.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the
+///   `ContextErrorHandlerFunction` trait.
+/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+pub fn recoverable_spawn_catch_context<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<()>
+where
+    F: RecoverableFunction,
+    E: ContextErrorHandlerFunction,
+{
+    spawn(move || {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
+        let run_result: SpawnResult = run_function_with_location(function);
+        if let Err(err) = run_result {
+            let message: String = spawn_error_to_string(err);
+            let location: Option<(String, u32, u32)> =
+                LAST_PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+            let backtrace: Option<String> = LAST_PANIC_BACKTRACE
+                .with(|cell| cell.borrow_mut().take())
+                .filter(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+                .map(|backtrace| backtrace.to_string());
+            let context: PanicContext = PanicContext {
+                message,
+                location,
+                backtrace,
+            };
+            error_handle_function(&context);
+        }
+    })
+}
+
+/// Spawns a recoverable function whose error-handling function receives the original typed
+/// panic payload when the panic value downcasts to `T`, falling back to its stringified form
+/// (via `spawn_error_to_string`) otherwise.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle the typed payload, implementing the
+///   `TypedErrorHandlerFunction<T>` trait.
+/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+pub fn recoverable_spawn_catch_typed<F, T, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<()>
+where
+    F: RecoverableFunction,
+    T: Any,
+    E: TypedErrorHandlerFunction<T>,
+{
+    spawn(move || {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
+        let run_result: SpawnResult = run_function(function);
+        if let Err(err) = run_result {
+            let payload: Result<Box<T>, String> = match downcast_payload::<T>(err) {
+                Ok(payload) => Ok(payload),
+                Err(err) => Err(spawn_error_to_string(err)),
+            };
+            error_handle_function(payload);
+        }
+    })
+}
+
+/// Spawns a recoverable function and returns a `RecoverableHandle` that preserves its
+/// `SpawnResult` instead of discarding it, and supports registering `on_panic` callbacks.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - Returns: A `RecoverableHandle` that can be joined, polled, or given panic callbacks.
+pub fn recoverable_spawn_handle<F>(function: F) -> RecoverableHandle
+where
+    F: RecoverableFunction,
+{
+    let state: Arc<Mutex<HandleState>> = Arc::new(Mutex::new(HandleState::default()));
+    let state_for_thread: Arc<Mutex<HandleState>> = Arc::clone(&state);
+    let join_handle: JoinHandle<()> = spawn(move || {
+        let _silent_guard: SilentGuard = SilentGuard::acquire();
+        let run_result: SpawnResult = run_function(function);
+        let message: Option<String> = match &run_result {
+            Err(err) => Some(spawn_error_to_string_ref(err)),
+            Ok(()) => None,
+        };
+        let mut guard = state_for_thread.lock().unwrap();
+        guard.error_message = message.clone();
+        guard.result = Some(run_result);
+        let callbacks: Vec<Box<dyn FnOnce(String) + Send>> =
+            std::mem::take(&mut guard.pending_callbacks);
+        drop(guard);
+        if let Some(message) = message {
+            for cb in callbacks {
+                cb(message.clone());
+            }
+        }
+    });
+    RecoverableHandle { join_handle, state }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    #[test]
+    fn recoverable_spawn_retry_retries_until_success() {
+        let attempts: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let attempts_for_worker: Arc<AtomicUsize> = Arc::clone(&attempts);
+        let handle: JoinHandle<()> = recoverable_spawn_retry(
+            move || {
+                if attempts_for_worker.fetch_add(1, AtomicOrdering::SeqCst) < 2 {
+                    panic!("retry me");
+                }
+            },
+            RetryPolicy::new(3),
+            |_err| {},
+        );
+        handle.join().unwrap();
+        assert_eq!(attempts.load(AtomicOrdering::SeqCst), 3);
+    }
+
+    #[test]
+    fn recoverable_spawn_retry_reports_last_error_after_exhausting_attempts() {
+        let last_error: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let last_error_for_handler: Arc<Mutex<Option<String>>> = Arc::clone(&last_error);
+        let handle: JoinHandle<()> = recoverable_spawn_retry(
+            || panic!("always fails"),
+            RetryPolicy::new(2),
+            move |err: &str| {
+                *last_error_for_handler.lock().unwrap() = Some(err.to_string());
+            },
+        );
+        handle.join().unwrap();
+        assert_eq!(last_error.lock().unwrap().as_deref(), Some("always fails"));
+    }
+}
+
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+
+    #[test]
+    fn recoverable_spawn_catch_context_reports_message_and_location() {
+        let captured: Arc<Mutex<Option<PanicContext>>> = Arc::new(Mutex::new(None));
+        let captured_for_handler: Arc<Mutex<Option<PanicContext>>> = Arc::clone(&captured);
+        let handle: JoinHandle<()> = recoverable_spawn_catch_context(
+            || panic!("context regression test"),
+            move |context: &PanicContext| {
+                *captured_for_handler.lock().unwrap() = Some(context.clone());
+            },
+        );
+        handle.join().unwrap();
+
+        let context: PanicContext = captured
+            .lock()
+            .unwrap()
+            .take()
+            .expect("error_handle_function should have run");
+        assert_eq!(context.message, "context regression test");
+        let (file, line, _column) = context.location.expect("location should be captured");
+        assert!(file.ends_with("sync.rs"));
+        assert!(line > 0);
+    }
+}
+
+#[cfg(test)]
+mod typed_tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct WorkerError {
+        code: u32,
+    }
+
+    #[test]
+    fn recoverable_spawn_catch_typed_recovers_the_typed_payload() {
+        let captured: Arc<Mutex<Option<Result<u32, String>>>> = Arc::new(Mutex::new(None));
+        let captured_for_handler: Arc<Mutex<Option<Result<u32, String>>>> = Arc::clone(&captured);
+        let handle: JoinHandle<()> = recoverable_spawn_catch_typed(
+            || std::panic::panic_any(WorkerError { code: 42 }),
+            move |payload: Result<Box<WorkerError>, String>| {
+                *captured_for_handler.lock().unwrap() = Some(payload.map(|err| err.code));
+            },
+        );
+        handle.join().unwrap();
+        assert_eq!(captured.lock().unwrap().take(), Some(Ok(42)));
+    }
+
+    #[test]
+    fn recoverable_spawn_catch_typed_falls_back_to_string_for_a_mismatched_type() {
+        let captured: Arc<Mutex<Option<Result<u32, String>>>> = Arc::new(Mutex::new(None));
+        let captured_for_handler: Arc<Mutex<Option<Result<u32, String>>>> = Arc::clone(&captured);
+        let handle: JoinHandle<()> = recoverable_spawn_catch_typed(
+            || panic!("not a WorkerError"),
+            move |payload: Result<Box<WorkerError>, String>| {
+                *captured_for_handler.lock().unwrap() = Some(payload.map(|err| err.code));
+            },
+        );
+        handle.join().unwrap();
+        assert_eq!(
+            captured.lock().unwrap().take(),
+            Some(Err("not a WorkerError".to_string()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod handle_tests {
+    use super::*;
+
+    #[test]
+    fn recoverable_spawn_handle_reports_ok_for_a_successful_function() {
+        let handle: RecoverableHandle = recoverable_spawn_handle(|| {});
+        assert!(handle.join().is_ok());
+    }
+
+    #[test]
+    fn recoverable_spawn_handle_reports_err_and_invokes_on_panic() {
+        let captured: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let captured_for_cb: Arc<Mutex<Option<String>>> = Arc::clone(&captured);
+
+        let handle: RecoverableHandle = recoverable_spawn_handle(|| panic!("handle regression test"))
+            .on_panic(move |message: String| {
+                *captured_for_cb.lock().unwrap() = Some(message);
+            });
+
+        let result: SpawnResult = handle.join();
+        assert!(result.is_err());
+        assert_eq!(
+            captured.lock().unwrap().as_deref(),
+            Some("handle regression test")
+        );
+    }
+
+    #[test]
+    fn recoverable_spawn_handle_on_panic_fires_for_an_already_finished_worker() {
+        let handle: RecoverableHandle = recoverable_spawn_handle(|| panic!("already done"));
+        // Wait for the worker thread to finish, so it has already recorded its result
+        // before `on_panic` is registered, exercising the "already finished" branch.
+        while !handle.is_finished() {
+            std::thread::yield_now();
+        }
+
+        let captured: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let captured_for_cb: Arc<Mutex<Option<String>>> = Arc::clone(&captured);
+        let handle: RecoverableHandle = handle.on_panic(move |message: String| {
+            *captured_for_cb.lock().unwrap() = Some(message);
+        });
+        handle.join().unwrap_err();
+
+        assert_eq!(captured.lock().unwrap().as_deref(), Some("already done"));
+    }
+}
+
+#[cfg(test)]
+mod catch_panic_tests {
+    use super::*;
+
+    #[test]
+    fn catch_panic_records_the_message_for_take_last_error_and_last_error_length() {
+        assert!(catch_panic(|| {}));
+        assert_eq!(last_error_length(), 0);
+
+        assert!(!catch_panic(|| panic!("inline failure")));
+        assert_eq!(last_error_length(), "inline failure".len());
+        assert_eq!(take_last_error().as_deref(), Some("inline failure"));
+        assert!(take_last_error().is_none());
+        assert_eq!(last_error_length(), 0);
+    }
+}
+
+#[cfg(test)]
+mod silent_tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    static HANDLER_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts only this module's own panics, so silenced worker panics from other tests
+    /// running in parallel while quiet mode is on do not disturb the counts asserted below.
+    fn counting_handler(info: &PanicHookInfo) {
+        let message: Option<&str> = info.payload().downcast_ref::<&str>().copied();
+        if matches!(
+            message,
+            Some("silenced worker panic") | Some("unrelated thread panic")
+        ) {
+            HANDLER_CALLS.fetch_add(1, AtomicOrdering::SeqCst);
+        }
+    }
+
+    /// Exercises both halves of the quiet-mode contract in one test (rather than splitting
+    /// across `#[test]` fns) because `SILENT`/`SILENT_HANDLER` are process-wide statics, and
+    /// `cargo test`'s default parallelism would otherwise let unrelated tests observe a
+    /// half-configured quiet mode.
+    #[test]
+    fn set_silent_routes_worker_panics_to_handler_and_leaves_other_threads_alone() {
+        set_silent_handler(Some(counting_handler));
+        set_silent(true);
+
+        let before_worker: usize = HANDLER_CALLS.load(AtomicOrdering::SeqCst);
+        let handle: JoinHandle<()> = recoverable_spawn(|| panic!("silenced worker panic"));
+        handle.join().unwrap();
+        let after_worker: usize = HANDLER_CALLS.load(AtomicOrdering::SeqCst);
+        assert_eq!(after_worker, before_worker + 1);
+
+        // Still silent, but this panic happens on a thread that never went through a
+        // recoverable_spawn* worker -- it must reach the real default hook, not ours.
+        let outside: JoinHandle<()> = std::thread::spawn(|| {
+            let _: SpawnResult = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                panic!("unrelated thread panic");
+            }));
+        });
+        outside.join().unwrap();
+        assert_eq!(HANDLER_CALLS.load(AtomicOrdering::SeqCst), after_worker);
+
+        set_silent(false);
+        set_silent_handler(None);
+    }
+}
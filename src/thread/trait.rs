@@ -1,3 +1,6 @@
+use crate::*;
+use std::any::Any;
+
 /// Trait alias for functions that can be executed in a recoverable context.
 ///
 /// - Functions implementing this trait must satisfy `Fn() + Send + Sync + 'static`.
@@ -12,3 +15,27 @@ impl<T> RecoverableFunction for T where T: Fn() + Send + Sync + 'static {}
 pub trait ErrorHandlerFunction: Fn(&str) + Send + Sync + 'static {}
 
 impl<T> ErrorHandlerFunction for T where T: Fn(&str) + Send + Sync + 'static {}
+
+/// Trait alias for error-handling functions that receive the full `PanicContext`
+/// (message, source location, and backtrace) of a caught panic.
+///
+/// - Functions implementing this trait must accept a `&PanicContext` and satisfy
+///   `Fn(&PanicContext) + Send + Sync + 'static`.
+pub trait ContextErrorHandlerFunction: Fn(&PanicContext) + Send + Sync + 'static {}
+
+impl<T> ContextErrorHandlerFunction for T where T: Fn(&PanicContext) + Send + Sync + 'static {}
+
+/// Trait alias for error-handling functions that receive the original typed panic payload.
+///
+/// - Functions implementing this trait must accept a `Result<Box<T>, String>` — the typed
+///   payload when the panic value downcasts to `T`, or its stringified form otherwise — and
+///   satisfy `Fn(Result<Box<T>, String>) + Send + Sync + 'static`.
+pub trait TypedErrorHandlerFunction<T: Any>:
+    Fn(Result<Box<T>, String>) + Send + Sync + 'static
+{
+}
+
+impl<T: Any, F> TypedErrorHandlerFunction<T> for F where
+    F: Fn(Result<Box<T>, String>) + Send + Sync + 'static
+{
+}
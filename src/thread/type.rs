@@ -1,5 +1,10 @@
 use crate::*;
-use std::{any::Any, sync::Arc};
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::Duration,
+};
 
 /// Type alias for a boxed dynamic type that implements `Any` and `Send`.
 ///
@@ -24,3 +29,142 @@ pub type BoxRecoverableFunction = Arc<dyn RecoverableFunction>;
 /// - This type represents an `Arc`-wrapped version of any function implementing the `ErrorHandlerFunction` trait.
 /// - Allows shared ownership and thread-safe handling of errors with custom logic across multiple threads.
 pub type BoxErrorHandlerFunction = Arc<dyn ErrorHandlerFunction>;
+
+/// Configuration describing how a recoverable function should be retried after a panic.
+///
+/// - `max_attempts`: The total number of times the function will be run before giving up,
+///   including the first attempt.
+/// - `backoff`: An optional delay slept (via `std::thread::sleep`) between attempts.
+/// - `multiplier`: An optional factor applied to `backoff` after every failed attempt,
+///   producing exponential backoff. Ignored when `backoff` is `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: usize,
+    pub backoff: Option<Duration>,
+    pub multiplier: Option<f64>,
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy` with the given number of attempts and no backoff.
+    ///
+    /// - `max_attempts`: The total number of times the function will be run before giving up.
+    /// - Returns: A `RetryPolicy` that retries immediately with no delay between attempts.
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            backoff: None,
+            multiplier: None,
+        }
+    }
+
+    /// Sets the delay slept between attempts.
+    ///
+    /// - `backoff`: The `Duration` to sleep between attempts.
+    /// - Returns: `Self` with the backoff applied, for chained configuration.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
+
+    /// Sets the multiplier applied to `backoff` after every failed attempt.
+    ///
+    /// - `multiplier`: The factor used to grow the backoff duration exponentially.
+    /// - Returns: `Self` with the multiplier applied, for chained configuration.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    /// Advances `backoff` by `multiplier` in place, producing the delay for the next attempt.
+    pub(crate) fn advance(&mut self) {
+        if let (Some(backoff), Some(multiplier)) = (self.backoff, self.multiplier) {
+            self.backoff = Some(backoff.mul_f64(multiplier));
+        }
+    }
+}
+
+/// The full context of a panic caught from a recoverable function, preserving information
+/// that `spawn_error_to_string` would otherwise discard.
+///
+/// - `message`: The stringified panic payload, as produced by `spawn_error_to_string`.
+/// - `location`: The `(file, line, column)` of the `panic!` call site, when available.
+/// - `backtrace`: A captured backtrace rendered to a string, when backtraces are enabled
+///   (see `std::backtrace::Backtrace`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanicContext {
+    pub message: String,
+    pub location: Option<(String, u32, u32)>,
+    pub backtrace: Option<String>,
+}
+
+/// Attempts to recover the original typed panic payload from a `BoxAnySend`.
+///
+/// - `err`: The captured error value, of type `BoxAnySend`.
+/// - Returns: `Ok` with the payload downcast to `T` on success, or the original `BoxAnySend`
+///   back in `Err` when it is not a `T`, so the caller can fall back to `spawn_error_to_string`.
+pub fn downcast_payload<T: Any>(err: BoxAnySend) -> Result<Box<T>, BoxAnySend> {
+    err.downcast::<T>()
+}
+
+/// Shared state through which a `recoverable_spawn_handle` worker thread reports its
+/// outcome back to the owning `RecoverableHandle`.
+#[derive(Default)]
+pub(crate) struct HandleState {
+    pub(crate) result: Option<SpawnResult>,
+    pub(crate) error_message: Option<String>,
+    pub(crate) pending_callbacks: Vec<Box<dyn FnOnce(String) + Send>>,
+}
+
+/// A handle to a spawned recoverable function that preserves its `SpawnResult`, instead of
+/// discarding it like the bare `JoinHandle<()>` returned by `recoverable_spawn`.
+///
+/// - Obtained from `recoverable_spawn_handle`.
+/// - `join` blocks for the outcome; `is_finished` polls without blocking; `on_panic` registers
+///   a callback fired with the panic message, whether the function has already panicked,
+///   is still running, or panics later.
+pub struct RecoverableHandle {
+    pub(crate) join_handle: JoinHandle<()>,
+    pub(crate) state: Arc<Mutex<HandleState>>,
+}
+
+impl RecoverableHandle {
+    /// Blocks until the spawned function finishes, returning its `SpawnResult`.
+    ///
+    /// - Returns: `Ok(())` if the function completed without panicking, or `Err(BoxAnySend)`
+    ///   with the captured panic payload otherwise.
+    pub fn join(self) -> SpawnResult {
+        let _ = self.join_handle.join();
+        let mut guard = self.state.lock().unwrap();
+        guard.result.take().unwrap_or(Ok(()))
+    }
+
+    /// Reports whether the spawned thread has finished running, without blocking.
+    ///
+    /// - Returns: `true` if the underlying thread has terminated.
+    pub fn is_finished(&self) -> bool {
+        self.join_handle.is_finished()
+    }
+
+    /// Registers a callback invoked with the panic message if/when the spawned function panics.
+    /// Builder-style: consumes and returns `Self` so calls can be chained onto
+    /// `recoverable_spawn_handle`.
+    ///
+    /// - `cb`: Invoked with the stringified panic payload. Fires immediately if the function
+    ///   has already panicked, or later from the worker thread once it does.
+    /// - Returns: `Self`, for chained configuration.
+    pub fn on_panic(self, cb: impl FnOnce(String) + Send + 'static) -> Self {
+        let mut guard = self.state.lock().unwrap();
+        let already_panicked: Option<String> = guard.error_message.clone();
+        let still_running: bool = already_panicked.is_none() && guard.result.is_none();
+        if still_running {
+            guard.pending_callbacks.push(Box::new(cb));
+            drop(guard);
+        } else {
+            drop(guard);
+            if let Some(message) = already_panicked {
+                cb(message);
+            }
+        }
+        self
+    }
+}